@@ -63,11 +63,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         Err(e) => {
             println!("[FAILED] Failed after retries: {}", e);
-            match &e {
-                ValidatorConfigError::RateLimitExceeded { retry_after, .. } => {
-                    if let Some(delay) = retry_after {
-                        println!("  [INFO] Server suggests waiting {} seconds", delay);
-                    }
+            match e.root_cause() {
+                ValidatorConfigError::RateLimitExceeded {
+                    retry_after: Some(delay),
+                    ..
+                } => {
+                    println!("  [INFO] Server suggests waiting {} seconds", delay);
                 }
                 ValidatorConfigError::HttpError { status, .. } => {
                     println!("  [INFO] HTTP status: {}", status);
@@ -6,8 +6,13 @@
 //! - Data parsing and processing time
 //! - Memory allocation patterns
 //! - Overall throughput
+//! - Account decoding on a synthetic RPC response, without network I/O
+//! - Response size and fetch time with and without gzip/brotli compression
+//!
+//! Run once with and once without `--features parallelism` to compare sequential and
+//! parallel decoding.
 
-use solana_validator_info::{SolanaNetwork, ValidatorConfigClient};
+use solana_validator_info::{ClientConfig, SolanaNetwork, ValidatorConfigClient};
 use std::time::Instant;
 
 #[tokio::main]
@@ -69,6 +74,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Performance breakdown
     println!("\nPerformance Analysis:");
+    benchmark_account_decoding(&client);
+    benchmark_compression(SolanaNetwork::Testnet).await?;
     benchmark_string_operations();
     benchmark_json_parsing().await;
 
@@ -102,6 +109,85 @@ fn estimate_memory_usage(validators: &[solana_validator_info::ValidatorInfo]) ->
         .sum()
 }
 
+fn benchmark_account_decoding(client: &ValidatorConfigClient) {
+    let mode = if cfg!(feature = "parallelism") {
+        "parallel"
+    } else {
+        "sequential"
+    };
+    println!("Account decoding benchmark ({mode}):");
+
+    let iterations = 200;
+    let response = include_str!("../tests/fixtures/config_accounts.json");
+    let benchmark = match client.measure_parse_performance(response, iterations) {
+        Ok(benchmark) => benchmark,
+        Err(e) => {
+            println!("Decoding benchmark failed: {e}");
+            return;
+        }
+    };
+    println!(
+        "Decoding: {:.0} accounts/s, {:.2} MB/s over {} runs",
+        benchmark.accounts_per_second,
+        benchmark.bytes_per_second / 1_048_576.0,
+        iterations
+    );
+    println!(
+        "Run latency: p50 {}us, p95 {}us, p99 {}us",
+        benchmark.p50_us, benchmark.p95_us, benchmark.p99_us
+    );
+}
+
+async fn benchmark_compression(network: SolanaNetwork) -> Result<(), Box<dyn std::error::Error>> {
+    println!("Compression benchmark:");
+
+    // Automatic decompression is disabled so the body length is the transfer size
+    let raw_client = reqwest::Client::builder().no_gzip().no_brotli().build()?;
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getProgramAccounts",
+        "params": [
+            "Config1111111111111111111111111111111111111",
+            { "encoding": "base64+zstd", "commitment": "confirmed" }
+        ]
+    });
+    for encoding in ["identity", "gzip", "br"] {
+        let response = raw_client
+            .post(network.rpc_url())
+            .header("Accept-Encoding", encoding)
+            .json(&request)
+            .send()
+            .await?;
+        let used = response
+            .headers()
+            .get("content-encoding")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("identity")
+            .to_string();
+        let bytes = response.bytes().await?.len();
+        println!(
+            "Accept-Encoding {encoding:>8}: {:.2} MB transferred (server used {used})",
+            bytes as f64 / 1_048_576.0
+        );
+    }
+
+    for enabled in [false, true] {
+        let config = ClientConfig::new().with_compression(enabled);
+        let client = ValidatorConfigClient::with_config(network.clone(), config);
+        let start = Instant::now();
+        let validators = client.fetch_all_validators().await?;
+        println!(
+            "Fetch with compression {}: {}ms, {} validators",
+            if enabled { "on " } else { "off" },
+            start.elapsed().as_millis(),
+            validators.len()
+        );
+    }
+
+    Ok(())
+}
+
 fn benchmark_string_operations() {
     println!("String operations benchmark:");
 
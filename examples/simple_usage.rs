@@ -5,8 +5,9 @@
 //! IMPORTANT: This library now returns the actual validator identity public keys
 //! that can be used to connect to validators, not Config Program account keys.
 
-use solana_validator_info::{SolanaNetwork, ValidatorConfigClient, ValidatorInfo};
+use solana_validator_info::{ClientConfig, SolanaNetwork, ValidatorConfigClient, ValidatorInfo};
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// Example struct showing how you might integrate validator data into your own types
 #[derive(Debug, Clone)]
@@ -107,40 +108,6 @@ async fn process_validators_for_my_app() -> Result<(), Box<dyn std::error::Error
     Ok(())
 }
 
-/// Example of how you might cache validator data
-use std::time::{Duration, Instant};
-
-struct ValidatorCache {
-    data: Vec<ValidatorInfo>,
-    last_updated: Instant,
-    cache_duration: Duration,
-}
-
-impl ValidatorCache {
-    fn new() -> Self {
-        Self {
-            data: Vec::new(),
-            last_updated: Instant::now()
-                .checked_sub(Duration::from_secs(3600))
-                .unwrap(), // Force initial fetch
-            cache_duration: Duration::from_secs(300), // 5 minutes
-        }
-    }
-
-    async fn get_validators(&mut self) -> Result<&[ValidatorInfo], Box<dyn std::error::Error>> {
-        if self.last_updated.elapsed() > self.cache_duration {
-            println!("Cache expired, fetching fresh data...");
-            let client = ValidatorConfigClient::new(SolanaNetwork::Mainnet);
-            self.data = client.fetch_all_validators().await?;
-            self.last_updated = Instant::now();
-        } else {
-            println!("Using cached data");
-        }
-
-        Ok(&self.data)
-    }
-}
-
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("=== Simple Integration Example ===\n");
@@ -150,16 +117,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("\n=== Caching Example ===\n");
 
-    // Example 2: Using cache
-    let mut cache = ValidatorCache::new();
+    // Example 2: Using the built-in cache (5 minutes)
+    let config = ClientConfig::new().with_cache_ttl(Duration::from_secs(300));
+    let client = ValidatorConfigClient::with_config(SolanaNetwork::Mainnet, config);
 
     // First call - will fetch from network
-    let validators1 = cache.get_validators().await?;
+    let validators1 = client.fetch_all_validators().await?;
     println!("First call returned {} validators", validators1.len());
 
     // Second call - will use cache
-    let validators2 = cache.get_validators().await?;
+    let validators2 = client.fetch_all_validators().await?;
     println!("Second call returned {} validators", validators2.len());
 
+    // Force the next call to fetch fresh data
+    client.invalidate_cache();
+
     Ok(())
 }
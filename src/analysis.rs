@@ -0,0 +1,258 @@
+//! Grouping and analysis of validator lists
+
+use crate::ValidatorInfo;
+use std::collections::HashMap;
+
+impl ValidatorInfo {
+    /// Host of the website as parsed by the `url` crate, without a leading `www.`
+    ///
+    /// The website must parse as an absolute URL with a host, so scheme-less values such as
+    /// `validator.example.com` return `None`, as does a missing website. The host comes
+    /// from [`url::Url::host_str`], so it is normalized the way browsers resolve it:
+    /// lowercased for `http`/`https`, IDNA-encoded, and IPv6 addresses keep their brackets.
+    #[must_use]
+    pub fn website_domain(&self) -> Option<String> {
+        url_domain(self.website.as_deref()?)
+    }
+}
+
+/// Host of an absolute URL without a leading `www.`, as described on
+/// [`ValidatorInfo::website_domain`]
+pub(crate) fn url_domain(url: &str) -> Option<String> {
+    let url = url::Url::parse(url.trim()).ok()?;
+    let host = url.host_str()?;
+    let domain = match host.get(..4) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("www.") => &host[4..],
+        _ => host,
+    };
+    (!domain.is_empty()).then(|| domain.to_string())
+}
+
+/// Group validators by [`ValidatorInfo::website_domain`], lowercased
+///
+/// Validators without a usable website are left out. Within each group validators keep
+/// their order in `validators`.
+#[must_use]
+pub fn group_by_domain(validators: &[ValidatorInfo]) -> HashMap<String, Vec<&ValidatorInfo>> {
+    let mut groups: HashMap<String, Vec<&ValidatorInfo>> = HashMap::new();
+    for info in validators {
+        if let Some(domain) = info.website_domain() {
+            groups
+                .entry(domain.to_ascii_lowercase())
+                .or_default()
+                .push(info);
+        }
+    }
+    groups
+}
+
+/// A validator list with lookup structures for repeated searches
+///
+/// Build it once from a fetched list with `ValidatorIndex::from(validators)`; lookups by
+/// identity are O(1) and name searches reuse precomputed lowercase names.
+#[derive(Debug, Clone, Default)]
+pub struct ValidatorIndex {
+    validators: Vec<ValidatorInfo>,
+    /// Position in `validators` of the most complete entry for each identity
+    by_identity: HashMap<String, usize>,
+    /// Lowercased `name` and `keybase_username` of each validator, in list order
+    lowercase: Vec<[Option<String>; 2]>,
+}
+
+impl From<Vec<ValidatorInfo>> for ValidatorIndex {
+    fn from(validators: Vec<ValidatorInfo>) -> Self {
+        let mut by_identity: HashMap<String, usize> = HashMap::new();
+        for (position, info) in validators.iter().enumerate() {
+            let Some(identity) = &info.validator_identity else {
+                continue;
+            };
+            by_identity
+                .entry(identity.clone())
+                .and_modify(|best| {
+                    if info.field_count() > validators[*best].field_count() {
+                        *best = position;
+                    }
+                })
+                .or_insert(position);
+        }
+
+        let lowercase = validators
+            .iter()
+            .map(|info| {
+                [&info.name, &info.keybase_username]
+                    .map(|field| field.as_deref().map(str::to_lowercase))
+            })
+            .collect();
+
+        Self {
+            validators,
+            by_identity,
+            lowercase,
+        }
+    }
+}
+
+impl ValidatorIndex {
+    /// Validators whose `name` or `keybase_username` contains `query`, in list order
+    ///
+    /// Without `case_sensitive`, both sides are compared in Unicode lowercase. An empty
+    /// query matches every validator with a name or Keybase username.
+    #[must_use]
+    pub fn search_by_name(&self, query: &str, case_sensitive: bool) -> Vec<&ValidatorInfo> {
+        let lowercase_query = query.to_lowercase();
+        self.validators
+            .iter()
+            .zip(&self.lowercase)
+            .filter(|(info, lowercase)| {
+                if case_sensitive {
+                    [&info.name, &info.keybase_username]
+                        .iter()
+                        .any(|field| field.as_deref().is_some_and(|value| value.contains(query)))
+                } else {
+                    lowercase.iter().any(|field| {
+                        field
+                            .as_deref()
+                            .is_some_and(|value| value.contains(&lowercase_query))
+                    })
+                }
+            })
+            .map(|(info, _)| info)
+            .collect()
+    }
+
+    /// The validator with identity `pubkey`
+    ///
+    /// If the list holds several entries for the identity, the most complete one is
+    /// returned (as in [`crate::deduplicate_by_identity`]).
+    #[must_use]
+    pub fn by_identity(&self, pubkey: &str) -> Option<&ValidatorInfo> {
+        self.by_identity
+            .get(pubkey)
+            .map(|&position| &self.validators[position])
+    }
+
+    /// Number of validators in the index, including duplicates
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    /// Whether the index holds no validators
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    /// The indexed validators, in their original order
+    #[must_use]
+    pub fn into_inner(self) -> Vec<ValidatorInfo> {
+        self.validators
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_website(name: &str, website: Option<&str>) -> ValidatorInfo {
+        ValidatorInfo {
+            validator_identity: None,
+            name: Some(name.to_string()),
+            website: website.map(str::to_string),
+            details: None,
+            keybase_username: None,
+            icon_url: None,
+            website_last_checked: None,
+        }
+    }
+
+    #[test]
+    fn test_website_domain() {
+        for (website, expected) in [
+            (Some("https://www.Example.com/about"), Some("example.com")),
+            (
+                Some("http://node.example.com:8080?x=1"),
+                Some("node.example.com"),
+            ),
+            (Some("https://user@wwwexample.org"), Some("wwwexample.org")),
+            (
+                Some("https://good.example\\@evil.example"),
+                Some("good.example"),
+            ),
+            (Some("http://[::1]/"), Some("[::1]")),
+            (Some("validator.example.com"), None),
+            (Some("https://"), None),
+            (Some("not a url"), None),
+            (None, None),
+        ] {
+            let info = with_website("Validator", website);
+            assert_eq!(info.website_domain().as_deref(), expected, "{website:?}");
+        }
+    }
+
+    #[test]
+    fn test_group_by_domain() {
+        let validators = [
+            with_website("One", Some("https://one.host.example")),
+            with_website("Two", Some("https://www.shared.example")),
+            with_website("Three", Some("https://Shared.example/three")),
+            with_website("Bare", Some("shared.example")),
+            with_website("None", None),
+        ];
+
+        let groups = group_by_domain(&validators);
+        assert_eq!(groups.len(), 2);
+        let shared: Vec<_> = groups["shared.example"]
+            .iter()
+            .map(|info| info.name.as_deref())
+            .collect();
+        assert_eq!(shared, [Some("Two"), Some("Three")]);
+        assert_eq!(groups["one.host.example"].len(), 1);
+    }
+
+    #[test]
+    fn test_validator_index() {
+        let validator = |identity: &str, name: Option<&str>, keybase: Option<&str>| ValidatorInfo {
+            validator_identity: Some(identity.to_string()),
+            name: name.map(str::to_string),
+            website: None,
+            details: None,
+            keybase_username: keybase.map(str::to_string),
+            icon_url: None,
+            website_last_checked: None,
+        };
+        let mut complete = validator("Ident2", Some("Staking Facilities"), Some("stakefac"));
+        complete.website = Some("https://stakingfacilities.example".to_string());
+        let index = ValidatorIndex::from(vec![
+            validator("Ident1", Some("Alpha Stake"), None),
+            validator("Ident2", Some("Staking Facilities"), None),
+            complete,
+            validator("Ident3", None, Some("STAKEcraft")),
+            validator("Ident4", Some("Beta"), None),
+        ]);
+        assert_eq!(index.len(), 5);
+
+        fn identities(found: Vec<&ValidatorInfo>) -> Vec<&str> {
+            found
+                .into_iter()
+                .map(|info| info.validator_identity.as_deref().unwrap())
+                .collect()
+        }
+        assert_eq!(
+            identities(index.search_by_name("stake", false)),
+            ["Ident1", "Ident2", "Ident3"]
+        );
+        assert_eq!(identities(index.search_by_name("Stake", true)), ["Ident1"]);
+        assert!(index.search_by_name("gamma", false).is_empty());
+
+        let best = index.by_identity("Ident2").unwrap();
+        assert!(best.website.is_some());
+        assert_eq!(
+            index.by_identity("Ident4").unwrap().name.as_deref(),
+            Some("Beta")
+        );
+        assert!(index.by_identity("Unknown").is_none());
+
+        assert_eq!(index.into_inner().len(), 5);
+    }
+}
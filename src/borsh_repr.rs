@@ -0,0 +1,197 @@
+//! Fixed-size, Borsh-serializable representation of validator info for on-chain programs
+//!
+//! On-chain programs (including Anchor programs receiving data over CPI) cannot work with
+//! variable-length JSON, so every string field is stored in a fixed-size byte array padded
+//! with zeroes, alongside a flag recording whether the field was present. Strings longer
+//! than their slot are truncated at a UTF-8 character boundary.
+
+use crate::{ValidatorConfigError, ValidatorInfo};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Size of the name slot in bytes
+pub const BORSH_NAME_LEN: usize = 50;
+/// Size of the website slot in bytes
+pub const BORSH_WEBSITE_LEN: usize = 100;
+/// Size of the details slot in bytes
+pub const BORSH_DETAILS_LEN: usize = 500;
+/// Size of the Keybase username slot in bytes
+pub const BORSH_KEYBASE_LEN: usize = 30;
+/// Size of the icon URL slot in bytes, the longest icon URL kept when decoding accounts
+pub const BORSH_ICON_URL_LEN: usize = 250;
+
+/// Anchor IDL-compatible validator info with fixed-size, zero-padded fields
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct BorshValidatorInfo {
+    /// Raw 32-byte validator identity public key (all zeroes if unknown)
+    pub identity: [u8; 32],
+    pub name: [u8; BORSH_NAME_LEN],
+    pub website: [u8; BORSH_WEBSITE_LEN],
+    pub details: [u8; BORSH_DETAILS_LEN],
+    pub keybase: [u8; BORSH_KEYBASE_LEN],
+    pub icon_url: [u8; BORSH_ICON_URL_LEN],
+    pub has_name: bool,
+    pub has_website: bool,
+    pub has_details: bool,
+    pub has_keybase: bool,
+    pub has_icon_url: bool,
+}
+
+/// Name kept for programs written against the original `anchor` feature
+#[cfg(feature = "anchor")]
+pub type AnchorValidatorInfo = BorshValidatorInfo;
+
+impl ValidatorInfo {
+    /// Convert into the fixed-size representation used by Anchor programs
+    ///
+    /// An identity that is not a valid base58 public key is stored as all zeroes.
+    #[must_use]
+    pub fn to_anchor_repr(&self) -> BorshValidatorInfo {
+        self.into()
+    }
+
+    /// Serialize the fixed-size representation with Borsh
+    #[must_use]
+    pub fn to_borsh_bytes(&self) -> Vec<u8> {
+        // Writing fixed-size arrays into a Vec cannot fail
+        borsh::to_vec(&self.to_anchor_repr()).unwrap_or_default()
+    }
+
+    /// Decode validator info from Borsh bytes produced by [`Self::to_borsh_bytes`]
+    ///
+    /// # Errors
+    /// Returns `ValidatorConfigError::Io` if the data is not a valid Borsh encoding, or
+    /// `ValidatorConfigError::Utf8` if a string field is not valid UTF-8
+    pub fn from_borsh_bytes(data: &[u8]) -> Result<Self, ValidatorConfigError> {
+        BorshValidatorInfo::try_from_slice(data)?.try_into()
+    }
+}
+
+impl From<&ValidatorInfo> for BorshValidatorInfo {
+    fn from(info: &ValidatorInfo) -> Self {
+        let identity = info
+            .validator_identity
+            .as_deref()
+            .and_then(|key| bs58::decode(key).into_vec().ok())
+            .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            .unwrap_or_default();
+
+        Self {
+            identity,
+            name: pack(info.name.as_deref()),
+            website: pack(info.website.as_deref()),
+            details: pack(info.details.as_deref()),
+            keybase: pack(info.keybase_username.as_deref()),
+            icon_url: pack(info.icon_url.as_deref()),
+            has_name: info.name.is_some(),
+            has_website: info.website.is_some(),
+            has_details: info.details.is_some(),
+            has_keybase: info.keybase_username.is_some(),
+            has_icon_url: info.icon_url.is_some(),
+        }
+    }
+}
+
+impl TryFrom<BorshValidatorInfo> for ValidatorInfo {
+    type Error = ValidatorConfigError;
+
+    fn try_from(repr: BorshValidatorInfo) -> Result<Self, Self::Error> {
+        Ok(Self {
+            validator_identity: (repr.identity != [0; 32])
+                .then(|| bs58::encode(repr.identity).into_string()),
+            name: unpack(&repr.name, repr.has_name)?,
+            website: unpack(&repr.website, repr.has_website)?,
+            details: unpack(&repr.details, repr.has_details)?,
+            keybase_username: unpack(&repr.keybase, repr.has_keybase)?,
+            icon_url: unpack(&repr.icon_url, repr.has_icon_url)?,
+            website_last_checked: None,
+        })
+    }
+}
+
+/// Copy a string into a zero-padded array, truncating at a character boundary
+fn pack<const N: usize>(value: Option<&str>) -> [u8; N] {
+    let mut slot = [0u8; N];
+    if let Some(value) = value {
+        let mut end = value.len().min(N);
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        slot[..end].copy_from_slice(&value.as_bytes()[..end]);
+    }
+    slot
+}
+
+/// Read a zero-padded array back into a string if its presence flag is set
+fn unpack(slot: &[u8], present: bool) -> Result<Option<String>, ValidatorConfigError> {
+    if !present {
+        return Ok(None);
+    }
+    let end = slot.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    Ok(Some(std::str::from_utf8(&slot[..end])?.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_borsh_round_trip() {
+        let info = ValidatorInfo {
+            validator_identity: Some("GwHH8ciFhR8vejWCqmg8FWZUCNtubPY2esALvy5tBvji".to_string()),
+            name: Some("Anchor Validator".to_string()),
+            website: Some("https://anchor.dev".to_string()),
+            details: None,
+            keybase_username: Some(String::new()),
+            icon_url: Some("https://anchor.dev/icon.png".to_string()),
+            website_last_checked: None,
+        };
+
+        let bytes = info.to_borsh_bytes();
+        assert_eq!(
+            bytes.len(),
+            32 + BORSH_NAME_LEN
+                + BORSH_WEBSITE_LEN
+                + BORSH_DETAILS_LEN
+                + BORSH_KEYBASE_LEN
+                + BORSH_ICON_URL_LEN
+                + 5
+        );
+
+        let decoded = ValidatorInfo::from_borsh_bytes(&bytes).unwrap();
+        assert_eq!(decoded.validator_identity, info.validator_identity);
+        assert_eq!(decoded.name, info.name);
+        assert_eq!(decoded.website, info.website);
+        assert_eq!(decoded.details, None);
+        // The presence flag keeps an empty string distinct from a missing field
+        assert_eq!(decoded.keybase_username, Some(String::new()));
+        assert_eq!(decoded.icon_url, info.icon_url);
+        assert_eq!(decoded, info);
+    }
+
+    #[test]
+    fn test_overlong_fields_truncate_on_char_boundary() {
+        let info = ValidatorInfo {
+            validator_identity: Some("not-a-pubkey".to_string()),
+            // 'é' is two bytes, so 50 bytes would split the 25th character
+            name: Some("é".repeat(40)),
+            website: Some("w".repeat(BORSH_WEBSITE_LEN + 10)),
+            details: None,
+            keybase_username: None,
+            icon_url: None,
+            website_last_checked: None,
+        };
+
+        let repr = BorshValidatorInfo::from(&info);
+        assert_eq!(repr.identity, [0; 32]);
+
+        let decoded = ValidatorInfo::try_from(repr).unwrap();
+        assert_eq!(decoded.validator_identity, None);
+        assert_eq!(decoded.name, Some("é".repeat(25)));
+        assert_eq!(decoded.website, Some("w".repeat(BORSH_WEBSITE_LEN)));
+    }
+
+    #[test]
+    fn test_invalid_borsh_bytes() {
+        assert!(ValidatorInfo::from_borsh_bytes(&[1, 2, 3]).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+//! CSV import and export of validator lists
+
+use crate::{csv_row, ValidatorConfigError, ValidatorInfo, CSV_HEADER};
+use serde::Deserialize;
+
+/// One CSV row, matched to the header by column name
+#[derive(Debug, Deserialize)]
+struct CsvRecord {
+    validator_identity: Option<String>,
+    name: Option<String>,
+    website: Option<String>,
+    details: Option<String>,
+    keybase_username: Option<String>,
+    icon_url: Option<String>,
+}
+
+/// Write validators as CSV with a header row
+///
+/// Columns are
+/// `validator_identity,name,website,details,keybase_username,icon_url,completeness_score`,
+/// the same rows [`crate::ValidatorConfigClient::fetch_validators_to_csv_stream`] writes.
+/// Fields containing commas, quotes or line breaks are quoted per RFC 4180, and missing
+/// fields are written as empty cells.
+///
+/// # Errors
+/// Returns `ValidatorConfigError::Io` if writing fails
+pub fn to_csv_writer<W: std::io::Write>(
+    validators: &[ValidatorInfo],
+    mut writer: W,
+) -> Result<(), ValidatorConfigError> {
+    writer.write_all(CSV_HEADER.as_bytes())?;
+    for info in validators {
+        writer.write_all(csv_row(info).as_bytes())?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Read validators from CSV written by [`to_csv_writer`]
+///
+/// Columns are matched by header name, so their order does not matter and extra columns
+/// (including `completeness_score`) are ignored. Empty cells become `None`.
+///
+/// # Errors
+/// Returns `ValidatorConfigError::Io` if reading fails or a row is malformed
+pub fn from_csv_reader<R: std::io::Read>(
+    reader: R,
+) -> Result<Vec<ValidatorInfo>, ValidatorConfigError> {
+    ::csv::Reader::from_reader(reader)
+        .deserialize::<CsvRecord>()
+        .map(|record| {
+            let record = record.map_err(std::io::Error::from)?;
+            Ok(ValidatorInfo {
+                validator_identity: record.validator_identity,
+                name: record.name,
+                website: record.website,
+                details: record.details,
+                keybase_username: record.keybase_username,
+                icon_url: record.icon_url,
+                website_last_checked: None,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_round_trip() {
+        let validators = vec![
+            ValidatorInfo {
+                validator_identity: Some(
+                    "GwHH8ciFhR8vejWCqmg8FWZUCNtubPY2esALvy5tBvji".to_string(),
+                ),
+                name: Some("Comma, Quote \" Validator".to_string()),
+                website: Some("https://csv.example".to_string()),
+                details: Some("Line one\nLine two".to_string()),
+                keybase_username: None,
+                icon_url: Some("https://csv.example/icon.png".to_string()),
+                website_last_checked: None,
+            },
+            ValidatorInfo {
+                validator_identity: None,
+                name: Some("Sparse".to_string()),
+                website: None,
+                details: None,
+                keybase_username: None,
+                icon_url: None,
+                website_last_checked: None,
+            },
+        ];
+
+        let mut output = Vec::new();
+        to_csv_writer(&validators, &mut output).unwrap();
+        let text = String::from_utf8(output.clone()).unwrap();
+        assert!(text.starts_with(
+            "validator_identity,name,website,details,keybase_username,icon_url,completeness_score\n"
+        ));
+        assert!(text.contains(r#""Comma, Quote "" Validator""#));
+        assert!(text.contains(",Sparse,,,,,0.2\n"));
+        assert!(!text.contains("None"));
+
+        let restored = from_csv_reader(output.as_slice()).unwrap();
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored[0].name, validators[0].name);
+        assert_eq!(restored[0].details, validators[0].details);
+        assert_eq!(restored[0].keybase_username, None);
+        assert_eq!(restored[0].icon_url, validators[0].icon_url);
+        assert_eq!(restored[1].validator_identity, None);
+        assert_eq!(restored[1].name.as_deref(), Some("Sparse"));
+    }
+
+    #[test]
+    fn test_from_csv_reader_rejects_malformed_rows() {
+        let input = "validator_identity,name\nabc,one,extra\n";
+        assert!(from_csv_reader(input.as_bytes()).is_err());
+    }
+}
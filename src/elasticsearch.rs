@@ -0,0 +1,150 @@
+//! Export validator info to Elasticsearch through the Bulk API
+
+use crate::{ValidatorConfigClient, ValidatorConfigError, ValidatorInfo};
+use serde::Deserialize;
+
+/// Index mapping applied when the target index does not exist yet
+///
+/// Identity, website, Keybase username and icon URL are exact-match `keyword` fields;
+/// name and details are analysed `text` for full-text search, with a `keyword` sub-field
+/// on name for sorting and aggregations.
+fn index_mapping() -> serde_json::Value {
+    serde_json::json!({
+        "mappings": {
+            "properties": {
+                "validator_identity": { "type": "keyword" },
+                "name": {
+                    "type": "text",
+                    "fields": { "keyword": { "type": "keyword", "ignore_above": 256 } }
+                },
+                "website": { "type": "keyword" },
+                "details": { "type": "text" },
+                "keybase_username": { "type": "keyword" },
+                "icon_url": { "type": "keyword" }
+            }
+        }
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct BulkResponse {
+    items: Vec<BulkItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BulkItem {
+    index: BulkItemResult,
+}
+
+#[derive(Debug, Deserialize)]
+struct BulkItemResult {
+    status: u16,
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+}
+
+impl ValidatorConfigClient {
+    /// Fetch all validators and index them into Elasticsearch with the Bulk API
+    ///
+    /// If `index_name` does not exist it is created with an explicit mapping for the
+    /// `ValidatorInfo` fields; an existing index is used as is. Documents use
+    /// `validator_identity` as `_id`, so re-running the export updates documents in place
+    /// instead of duplicating them. Validators without an identity get an ID generated by
+    /// Elasticsearch.
+    ///
+    /// All documents are sent in a single bulk request. Documents rejected by
+    /// Elasticsearch are logged and not counted.
+    ///
+    /// Returns the number of documents indexed.
+    ///
+    /// # Errors
+    /// Returns `ValidatorConfigError` if fetching validators fails, Elasticsearch answers
+    /// with an error status, or its response cannot be parsed
+    pub async fn export_to_elasticsearch(
+        &self,
+        base_url: &str,
+        index_name: &str,
+    ) -> Result<usize, ValidatorConfigError> {
+        let validators = self.fetch_validators_deduplicated().await?;
+        let base_url = base_url.trim_end_matches('/');
+
+        let response = self
+            .http_client
+            .put(format!("{base_url}/{index_name}"))
+            .json(&index_mapping())
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            // Elasticsearch answers 400 with this error type when the index already exists
+            if !body.contains("resource_already_exists_exception") {
+                return Err(ValidatorConfigError::HttpError {
+                    status: status.as_u16(),
+                    message: format!("Failed to create index {index_name}: {body}"),
+                });
+            }
+        }
+
+        if validators.is_empty() {
+            return Ok(0);
+        }
+
+        let response = self
+            .http_client
+            .post(format!("{base_url}/_bulk"))
+            .header(reqwest::header::CONTENT_TYPE, "application/x-ndjson")
+            .body(bulk_body(index_name, &validators)?)
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ValidatorConfigError::HttpError {
+                status: status.as_u16(),
+                message: format!("Bulk request failed: {body}"),
+            });
+        }
+
+        let bulk: BulkResponse = serde_json::from_str(&response.text().await?)?;
+        let mut indexed = 0;
+        for item in bulk.items {
+            if (200..300).contains(&item.index.status) {
+                indexed += 1;
+            } else {
+                log::warn!(
+                    "Elasticsearch rejected document with status {}: {}",
+                    item.index.status,
+                    item.index.error.unwrap_or_default()
+                );
+            }
+        }
+
+        log::info!(
+            "Indexed {indexed} of {} validators into Elasticsearch index {index_name}",
+            validators.len()
+        );
+        Ok(indexed)
+    }
+}
+
+/// Build the newline-delimited action/document pairs for a bulk index request
+fn bulk_body(
+    index_name: &str,
+    validators: &[ValidatorInfo],
+) -> Result<Vec<u8>, ValidatorConfigError> {
+    let mut body = Vec::new();
+    for info in validators {
+        let action = match &info.validator_identity {
+            Some(identity) => {
+                serde_json::json!({ "index": { "_index": index_name, "_id": identity } })
+            }
+            None => serde_json::json!({ "index": { "_index": index_name } }),
+        };
+        serde_json::to_writer(&mut body, &action)?;
+        body.push(b'\n');
+        serde_json::to_writer(&mut body, info)?;
+        body.push(b'\n');
+    }
+    Ok(body)
+}
@@ -0,0 +1,150 @@
+//! Dynamic GraphQL object types via `async-graphql`
+
+use crate::ValidatorInfo;
+use async_graphql::dynamic::{Field, FieldFuture, FieldValue, Object, TypeRef};
+use async_graphql::Value;
+
+/// GraphQL type name of the object type built by [`ValidatorInfo::graphql_type`]
+pub const GRAPHQL_TYPE_NAME: &str = "ValidatorInfo";
+
+/// Reads one string field of a validator
+type FieldGetter = fn(&ValidatorInfo) -> &Option<String>;
+
+/// Nullable string fields of the GraphQL type and how to read them
+const STRING_FIELDS: [(&str, FieldGetter); 6] = [
+    ("validator_identity", |info| &info.validator_identity),
+    ("name", |info| &info.name),
+    ("website", |info| &info.website),
+    ("details", |info| &info.details),
+    ("keybase_username", |info| &info.keybase_username),
+    ("icon_url", |info| &info.icon_url),
+];
+
+impl ValidatorInfo {
+    /// The `async-graphql` dynamic object type for validators, named [`GRAPHQL_TYPE_NAME`]
+    ///
+    /// Fields use the same names as the JSON serialization: `validator_identity`, `name`,
+    /// `website`, `details`, `keybase_username` and `icon_url` are nullable `String`s and
+    /// absent values resolve to `null`; `completeness_score` is a non-null `Float`.
+    ///
+    /// Register the type once on a `dynamic::Schema`. Its resolvers read the validator from
+    /// the parent value, so any field of this type, including lists, can return values
+    /// built by [`Self::to_graphql_object`].
+    #[must_use]
+    pub fn graphql_type() -> Object {
+        let object = STRING_FIELDS.into_iter().fold(
+            Object::new(GRAPHQL_TYPE_NAME),
+            |object, (name, value)| {
+                object.field(validator_field(
+                    name,
+                    TypeRef::named(TypeRef::STRING),
+                    move |info| value(info).clone().map_or(Value::Null, Value::String),
+                ))
+            },
+        );
+
+        object.field(validator_field(
+            "completeness_score",
+            TypeRef::named_nn(TypeRef::FLOAT),
+            |info| Value::from(f64::from(info.completeness_score())),
+        ))
+    }
+
+    /// This validator as a value of the [`Self::graphql_type`] object type
+    ///
+    /// Return it from a resolver, or collect several with `FieldValue::list` for a
+    /// `[ValidatorInfo]` field.
+    #[must_use]
+    pub fn to_graphql_object(&self) -> FieldValue<'static> {
+        FieldValue::owned_any(self.clone())
+    }
+}
+
+/// A field resolving to `resolve` applied to the parent [`ValidatorInfo`]
+fn validator_field(
+    name: &str,
+    ty: TypeRef,
+    resolve: impl Fn(&ValidatorInfo) -> Value + Send + Sync + 'static,
+) -> Field {
+    let resolve = std::sync::Arc::new(resolve);
+    Field::new(name, ty, move |ctx| {
+        let resolve = std::sync::Arc::clone(&resolve);
+        FieldFuture::new(async move {
+            let info = ctx.parent_value.try_downcast_ref::<ValidatorInfo>()?;
+            Ok(Some(resolve(info)))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::dynamic::Schema;
+
+    #[tokio::test]
+    async fn test_graphql_list_of_validators() {
+        let validators = vec![
+            ValidatorInfo {
+                validator_identity: Some("Ident1111".to_string()),
+                name: Some("Test Validator".to_string()),
+                website: Some("https://example.com".to_string()),
+                details: None,
+                keybase_username: None,
+                icon_url: None,
+                website_last_checked: None,
+            },
+            ValidatorInfo {
+                validator_identity: Some("Ident2222".to_string()),
+                name: None,
+                website: None,
+                details: Some("Second".to_string()),
+                keybase_username: Some("second".to_string()),
+                icon_url: Some("https://example.com/icon.png".to_string()),
+                website_last_checked: None,
+            },
+        ];
+
+        let listed = validators.clone();
+        let query_root = Object::new("Query").field(Field::new(
+            "validators",
+            TypeRef::named_nn_list_nn(GRAPHQL_TYPE_NAME),
+            move |_| {
+                let listed = listed.clone();
+                FieldFuture::new(async move {
+                    Ok(Some(FieldValue::list(
+                        listed.iter().map(ValidatorInfo::to_graphql_object),
+                    )))
+                })
+            },
+        ));
+        let schema = Schema::build("Query", None, None)
+            .register(ValidatorInfo::graphql_type())
+            .register(query_root)
+            .finish()
+            .unwrap();
+
+        let response = schema
+            .execute(
+                "{ validators { validator_identity name website details keybase_username icon_url completeness_score } }",
+            )
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        let listed = data["validators"].as_array().unwrap();
+        assert_eq!(listed.len(), 2);
+
+        assert_eq!(listed[0]["validator_identity"], "Ident1111");
+        assert_eq!(listed[0]["name"], "Test Validator");
+        assert_eq!(listed[0]["website"], "https://example.com");
+        assert!(listed[0]["details"].is_null());
+        assert!(listed[0]["keybase_username"].is_null());
+        assert!(listed[0]["icon_url"].is_null());
+        let score = listed[0]["completeness_score"].as_f64().unwrap();
+        assert!((score - f64::from(validators[0].completeness_score())).abs() < 1e-6);
+
+        assert_eq!(listed[1]["validator_identity"], "Ident2222");
+        assert!(listed[1]["name"].is_null());
+        assert_eq!(listed[1]["keybase_username"], "second");
+        assert_eq!(listed[1]["icon_url"], "https://example.com/icon.png");
+    }
+}
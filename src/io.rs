@@ -0,0 +1,120 @@
+//! Persisting validator lists to disk
+
+use crate::{csv_row, ValidatorConfigClient, ValidatorConfigError, ValidatorInfo, CSV_HEADER};
+use std::path::{Path, PathBuf};
+
+/// File format used by [`ValidatorConfigClient::export_to_file`] and
+/// [`ValidatorConfigClient::import_from_file`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// A single compact JSON array
+    Json,
+    /// A single indented JSON array
+    JsonPretty,
+    /// One JSON object per line (NDJSON)
+    JsonLines,
+    /// CSV with a header row, in the columns of
+    /// [`ValidatorConfigClient::fetch_validators_to_csv_stream`]
+    ///
+    /// Exporting is always available; importing requires the `csv` feature.
+    Csv,
+}
+
+/// Serialize validators in `format`
+fn encode(
+    validators: &[ValidatorInfo],
+    format: ExportFormat,
+) -> Result<Vec<u8>, ValidatorConfigError> {
+    let mut data = Vec::new();
+    match format {
+        ExportFormat::Json => serde_json::to_writer(&mut data, validators)?,
+        ExportFormat::JsonPretty => serde_json::to_writer_pretty(&mut data, validators)?,
+        ExportFormat::JsonLines => {
+            for info in validators {
+                serde_json::to_writer(&mut data, info)?;
+                data.push(b'\n');
+            }
+        }
+        ExportFormat::Csv => {
+            data.extend_from_slice(CSV_HEADER.as_bytes());
+            for info in validators {
+                data.extend_from_slice(csv_row(info).as_bytes());
+            }
+        }
+    }
+    Ok(data)
+}
+
+/// Parse validators written in `format`
+fn decode(data: &[u8], format: ExportFormat) -> Result<Vec<ValidatorInfo>, ValidatorConfigError> {
+    match format {
+        ExportFormat::Json | ExportFormat::JsonPretty => Ok(serde_json::from_slice(data)?),
+        ExportFormat::JsonLines => Ok(std::str::from_utf8(data)?
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()?),
+        #[cfg(feature = "csv")]
+        ExportFormat::Csv => crate::from_csv_reader(data),
+        #[cfg(not(feature = "csv"))]
+        ExportFormat::Csv => Err(ValidatorConfigError::InvalidConfig(
+            "Importing CSV requires the `csv` feature".to_string(),
+        )),
+    }
+}
+
+/// Sibling of `path` with `.tmp` appended to the file name
+fn temporary_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".tmp");
+    path.with_file_name(file_name)
+}
+
+impl ValidatorConfigClient {
+    /// Fetch all validators and write them to the file at `path` in `format`
+    ///
+    /// Makes one [`Self::fetch_all_validators`] call. The file is written atomically: the
+    /// data goes to `<path>.tmp` first, which is then renamed over `path`, so readers never
+    /// see a partial file. Local bookkeeping such as
+    /// [`ValidatorInfo::website_last_checked`] is not written.
+    ///
+    /// Returns the number of validators written.
+    ///
+    /// # Errors
+    /// Returns `ValidatorConfigError` if fetching fails or the file cannot be written
+    pub async fn export_to_file(
+        &self,
+        path: &Path,
+        format: ExportFormat,
+    ) -> Result<usize, ValidatorConfigError> {
+        let validators = self.fetch_all_validators().await?;
+        let data = encode(&validators, format)?;
+
+        let temporary = temporary_path(path);
+        tokio::fs::write(&temporary, &data).await?;
+        if let Err(e) = tokio::fs::rename(&temporary, path).await {
+            let _ = tokio::fs::remove_file(&temporary).await;
+            return Err(e.into());
+        }
+
+        log::info!(
+            "Exported {} validators to {}",
+            validators.len(),
+            path.display()
+        );
+        Ok(validators.len())
+    }
+
+    /// Read validators written by [`Self::export_to_file`] without any network call
+    ///
+    /// # Errors
+    /// Returns `ValidatorConfigError::InvalidConfig` for [`ExportFormat::Csv`] without the
+    /// `csv` feature, or another `ValidatorConfigError` if the file cannot be read or is not
+    /// valid `format`
+    pub async fn import_from_file(
+        path: &Path,
+        format: ExportFormat,
+    ) -> Result<Vec<ValidatorInfo>, ValidatorConfigError> {
+        decode(&tokio::fs::read(path).await?, format)
+    }
+}
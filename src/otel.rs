@@ -0,0 +1,90 @@
+//! OpenTelemetry span attributes
+
+use crate::ValidatorInfo;
+use opentelemetry::KeyValue;
+
+impl ValidatorInfo {
+    /// Validator metadata as OpenTelemetry attributes, for annotating tracing spans
+    ///
+    /// Produces, in order:
+    /// - `validator.identity` (string), only when the identity is known
+    /// - `validator.name` (string), only when a name is published
+    /// - `validator.has_website`, `validator.has_details`, `validator.has_keybase` and
+    ///   `validator.has_icon_url` (bool)
+    /// - `validator.completeness_score` (f64, see [`ValidatorInfo::completeness_score`])
+    ///
+    /// Website, details, Keybase and icon URL values are reduced to flags to keep free text and
+    /// URLs out of trace backends.
+    #[must_use]
+    pub fn to_otel_attributes(&self) -> Vec<KeyValue> {
+        let mut attributes = Vec::with_capacity(7);
+        if let Some(identity) = &self.validator_identity {
+            attributes.push(KeyValue::new("validator.identity", identity.clone()));
+        }
+        if let Some(name) = &self.name {
+            attributes.push(KeyValue::new("validator.name", name.clone()));
+        }
+        attributes.extend([
+            KeyValue::new("validator.has_website", self.website.is_some()),
+            KeyValue::new("validator.has_details", self.details.is_some()),
+            KeyValue::new("validator.has_keybase", self.keybase_username.is_some()),
+            KeyValue::new("validator.has_icon_url", self.icon_url.is_some()),
+            KeyValue::new(
+                "validator.completeness_score",
+                f64::from(self.completeness_score()),
+            ),
+        ]);
+        attributes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::Value;
+
+    #[test]
+    fn test_to_otel_attributes() {
+        let info = ValidatorInfo {
+            validator_identity: Some("Ident1111".to_string()),
+            name: Some("Traced".to_string()),
+            website: Some("https://traced.example".to_string()),
+            details: None,
+            keybase_username: None,
+            icon_url: None,
+            website_last_checked: None,
+        };
+
+        let attributes: Vec<(String, Value)> = info
+            .to_otel_attributes()
+            .into_iter()
+            .map(|kv| (kv.key.to_string(), kv.value))
+            .collect();
+        let keys: Vec<&str> = attributes.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(
+            keys,
+            [
+                "validator.identity",
+                "validator.name",
+                "validator.has_website",
+                "validator.has_details",
+                "validator.has_keybase",
+                "validator.has_icon_url",
+                "validator.completeness_score",
+            ]
+        );
+        assert_eq!(attributes[0].1, Value::from("Ident1111"));
+        assert_eq!(attributes[1].1, Value::from("Traced"));
+        assert_eq!(attributes[2].1, Value::Bool(true));
+        assert_eq!(attributes[3].1, Value::Bool(false));
+        assert_eq!(attributes[5].1, Value::Bool(false));
+        assert!(matches!(attributes[6].1, Value::F64(score) if score > 0.0));
+
+        let anonymous = ValidatorInfo {
+            validator_identity: None,
+            name: None,
+            ..info
+        };
+        assert_eq!(anonymous.to_otel_attributes().len(), 5);
+    }
+}
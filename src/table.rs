@@ -0,0 +1,113 @@
+//! Terminal table rendering of validator info
+
+use crate::ValidatorInfo;
+use comfy_table::{presets::UTF8_FULL, Table};
+
+/// Column headers for multi-validator tables
+const HEADERS: [&str; 6] = [
+    "Identity", "Name", "Website", "Details", "Keybase", "Icon URL",
+];
+
+impl ValidatorInfo {
+    /// Render as a two-column `Field | Value` table for terminal display
+    ///
+    /// Only fields that are present get a row.
+    #[must_use]
+    pub fn to_pretty_table(&self) -> String {
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL).set_header(["Field", "Value"]);
+        for (header, value) in HEADERS.iter().zip(self.table_cells()) {
+            if let Some(value) = value {
+                table.add_row([*header, value]);
+            }
+        }
+        table.to_string()
+    }
+
+    /// Field values in [`HEADERS`] order
+    fn table_cells(&self) -> [Option<&str>; 6] {
+        [
+            self.validator_identity.as_deref(),
+            self.name.as_deref(),
+            self.website.as_deref(),
+            self.details.as_deref(),
+            self.keybase_username.as_deref(),
+            self.icon_url.as_deref(),
+        ]
+    }
+}
+
+/// Render validators as a table with one row per validator
+///
+/// Missing fields are shown as empty cells.
+#[must_use]
+pub fn validators_to_pretty_table(validators: &[ValidatorInfo]) -> String {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(HEADERS);
+    for info in validators {
+        table.add_row(info.table_cells().map(Option::unwrap_or_default));
+    }
+    table.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(name: &str, website: Option<&str>) -> ValidatorInfo {
+        ValidatorInfo {
+            validator_identity: None,
+            name: Some(name.to_string()),
+            website: website.map(str::to_string),
+            details: None,
+            keybase_username: None,
+            icon_url: None,
+            website_last_checked: None,
+        }
+    }
+
+    /// Data rows of a rendered table: lines with cell content, minus the header
+    fn data_rows(table: &str) -> Vec<Vec<String>> {
+        table
+            .lines()
+            .filter(|line| line.starts_with('│'))
+            .skip(1)
+            .map(|line| {
+                line.trim_matches('│')
+                    .split(['│', '┆'])
+                    .map(|cell| cell.trim().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_to_pretty_table() {
+        let mut info = validator("Table Validator", Some("https://table.example"));
+        info.icon_url = Some("https://table.example/icon.png".to_string());
+        assert_eq!(
+            data_rows(&info.to_pretty_table()),
+            vec![
+                vec!["Name", "Table Validator"],
+                vec!["Website", "https://table.example"],
+                vec!["Icon URL", "https://table.example/icon.png"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validators_to_pretty_table() {
+        let validators = [
+            validator("First", None),
+            validator("Second", Some("https://second.example")),
+            validator("Third", None),
+        ];
+
+        let rows = data_rows(&validators_to_pretty_table(&validators));
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].len(), 6);
+        assert_eq!(rows[1][1], "Second");
+        assert_eq!(rows[1][2], "https://second.example");
+        assert_eq!(rows[2][2], "");
+    }
+}
@@ -0,0 +1,422 @@
+//! Live Config account updates over the Solana RPC WebSocket API
+
+use crate::{
+    is_valid_solana_pubkey, AccountData, AccountEntry, ParsedAccount, RpcResponse, SolanaNetwork,
+    ValidatorConfigClient, ValidatorConfigError, ValidatorInfo, SOLANA_CONFIG_PROGRAM_ID,
+    VALIDATOR_INFO_ACCOUNT_SIZE,
+};
+use futures::{SinkExt, Stream, StreamExt};
+use serde::Deserialize;
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+type Socket = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// Delay before the first reconnection attempt, doubled after each failed attempt
+const INITIAL_RECONNECT_DELAY: Duration = Duration::from_millis(500);
+
+/// Upper bound for the delay between reconnection attempts
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+impl From<tungstenite::Error> for ValidatorConfigError {
+    fn from(e: tungstenite::Error) -> Self {
+        Self::WebSocket(e.to_string())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Notification<T> {
+    method: String,
+    params: NotificationParams<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NotificationParams<T> {
+    result: NotificationResult<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NotificationResult<T> {
+    value: T,
+}
+
+/// WebSocket URL served alongside an HTTP(S) RPC endpoint
+///
+/// `https://` becomes `wss://` and `http://` becomes `ws://`. An explicit port is
+/// incremented by one, as Solana validators serve WebSocket RPC on the port after the
+/// HTTP one (`http://127.0.0.1:8899` becomes `ws://127.0.0.1:8900`); URLs on the default
+/// port are kept as they are.
+fn websocket_url(rpc_url: &str) -> String {
+    let Ok(mut url) = url::Url::parse(rpc_url) else {
+        return rpc_url.to_string();
+    };
+    let scheme = match url.scheme() {
+        "https" => "wss",
+        "http" => "ws",
+        _ => return rpc_url.to_string(),
+    };
+    let port = url.port().and_then(|port| port.checked_add(1));
+    // Both schemes are "special", so switching between them and setting a port succeed
+    let _ = url.set_scheme(scheme);
+    if port.is_some() {
+        let _ = url.set_port(port);
+    }
+    url.to_string()
+}
+
+/// Delay before reconnection attempt `failed_reconnects + 1`
+fn reconnect_delay(failed_reconnects: u32) -> Duration {
+    INITIAL_RECONNECT_DELAY
+        .saturating_mul(2u32.saturating_pow(failed_reconnects))
+        .min(MAX_RECONNECT_DELAY)
+}
+
+/// Open a WebSocket connection, send a subscription request and wait for it to be confirmed
+async fn open_subscription(
+    url: &str,
+    method: &str,
+    params: serde_json::Value,
+) -> Result<Socket, ValidatorConfigError> {
+    let (mut socket, _) = tokio_tungstenite::connect_async(url).await?;
+
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params
+    });
+    socket.send(Message::text(request.to_string())).await?;
+
+    while let Some(message) = socket.next().await {
+        if let Message::Text(text) = message? {
+            let response: RpcResponse<u64> = serde_json::from_str(text.as_str())?;
+            let subscription = response.into_result()?;
+            log::debug!("{method} confirmed (subscription {subscription})");
+            return Ok(socket);
+        }
+    }
+
+    Err(ValidatorConfigError::WebSocket(
+        "Connection closed before the subscription was confirmed".to_string(),
+    ))
+}
+
+/// A message read from a [`Subscription`]
+enum SubscriptionEvent {
+    /// A text message, usually a notification
+    Text(String),
+    /// The connection was closed (`None`) or failed; the next read resubscribes
+    Disconnected(Option<tungstenite::Error>),
+}
+
+/// A WebSocket subscription that resubscribes when its connection drops
+///
+/// A dropped connection is reopened right away on the next read. Only failed attempts
+/// back off, waiting [`reconnect_delay`] before each further attempt.
+struct Subscription {
+    url: String,
+    method: &'static str,
+    params: serde_json::Value,
+    socket: Option<Socket>,
+    failed_reconnects: u32,
+}
+
+impl Subscription {
+    /// Open the connection and wait for the subscription to be confirmed
+    async fn open(
+        url: String,
+        method: &'static str,
+        params: serde_json::Value,
+    ) -> Result<Self, ValidatorConfigError> {
+        let socket = open_subscription(&url, method, params.clone()).await?;
+        Ok(Self {
+            url,
+            method,
+            params,
+            socket: Some(socket),
+            failed_reconnects: 0,
+        })
+    }
+
+    /// Wait for the next text message or disconnection
+    ///
+    /// # Errors
+    /// Returns the error of a failed reconnection attempt; the next call tries again
+    async fn next_event(&mut self) -> Result<SubscriptionEvent, ValidatorConfigError> {
+        loop {
+            let Some(socket) = self.socket.as_mut() else {
+                if self.failed_reconnects > 0 {
+                    tokio::time::sleep(reconnect_delay(self.failed_reconnects - 1)).await;
+                }
+                match open_subscription(&self.url, self.method, self.params.clone()).await {
+                    Ok(socket) => {
+                        self.socket = Some(socket);
+                        self.failed_reconnects = 0;
+                        continue;
+                    }
+                    Err(e) => {
+                        self.failed_reconnects = self.failed_reconnects.saturating_add(1);
+                        return Err(e);
+                    }
+                }
+            };
+
+            match socket.next().await {
+                Some(Ok(Message::Text(text))) => {
+                    return Ok(SubscriptionEvent::Text(text.as_str().to_string()));
+                }
+                Some(Ok(Message::Close(_))) | None => {
+                    self.socket = None;
+                    return Ok(SubscriptionEvent::Disconnected(None));
+                }
+                Some(Err(e)) => {
+                    self.socket = None;
+                    return Ok(SubscriptionEvent::Disconnected(Some(e)));
+                }
+                // Pings are answered by tungstenite itself
+                Some(Ok(_)) => {}
+            }
+        }
+    }
+}
+
+/// State of an `accountSubscribe` stream between polls
+struct AccountSubscription {
+    client: ValidatorConfigClient,
+    pubkey: String,
+    subscription: Subscription,
+}
+
+impl AccountSubscription {
+    /// Wait for the next account update, reconnecting if the connection drops
+    async fn next_update(&mut self) -> Result<ValidatorInfo, ValidatorConfigError> {
+        loop {
+            match self.subscription.next_event().await? {
+                SubscriptionEvent::Text(text) => {
+                    if let Some(result) = self.parse_notification(&text) {
+                        return result;
+                    }
+                }
+                SubscriptionEvent::Disconnected(None) => {
+                    log::warn!("WebSocket for account {} closed, reconnecting", self.pubkey);
+                }
+                SubscriptionEvent::Disconnected(Some(e)) => {
+                    log::warn!(
+                        "WebSocket for account {} failed: {e}, reconnecting",
+                        self.pubkey
+                    );
+                }
+            }
+        }
+    }
+
+    /// Decode an `accountNotification`, or `None` for messages that yield nothing
+    fn parse_notification(
+        &self,
+        text: &str,
+    ) -> Option<Result<ValidatorInfo, ValidatorConfigError>> {
+        let notification = match serde_json::from_str::<Notification<AccountData>>(text) {
+            Ok(notification) if notification.method == "accountNotification" => notification,
+            _ => {
+                log::debug!("Ignoring WebSocket message: {text}");
+                return None;
+            }
+        };
+
+        let entry = AccountEntry {
+            pubkey: self.pubkey.clone(),
+            account: notification.params.result.value,
+        };
+        match self.client.parse_account(entry) {
+            ParsedAccount::Validator(info) => Some(Ok(info)),
+            ParsedAccount::Empty => None,
+            ParsedAccount::Unrecognized(pubkey) => {
+                Some(Err(ValidatorConfigError::AccountParse { pubkey }))
+            }
+        }
+    }
+}
+
+impl ValidatorConfigClient {
+    /// Subscribe to changes of a single Config account over the RPC WebSocket API
+    ///
+    /// Opens an `accountSubscribe` subscription on the WebSocket endpoint of the client's
+    /// RPC URL (`https://` becomes `wss://`, `http://` becomes `ws://`, and an explicit
+    /// port is incremented by one, so `:8899` becomes `:8900`) and yields the
+    /// decoded validator info every time the account changes. Updates are decoded like
+    /// fetched accounts: empty configs are skipped unless
+    /// [`ClientConfig::include_empty_configs`](crate::ClientConfig::include_empty_configs)
+    /// is set, and data that is not validator info yields
+    /// `ValidatorConfigError::AccountParse`.
+    ///
+    /// If the connection drops, the stream reconnects and resubscribes right away; failed
+    /// attempts are retried with exponential backoff from 500ms up to 30s, yielding the
+    /// error of each. The stream never ends on its own; drop it to unsubscribe.
+    ///
+    /// # Errors
+    /// Returns `ValidatorConfigError::InvalidConfig` if `config_pubkey` is not a valid
+    /// public key, or an error if the first connection or subscription fails
+    pub async fn subscribe_to_account_changes(
+        &self,
+        config_pubkey: &str,
+    ) -> Result<impl Stream<Item = Result<ValidatorInfo, ValidatorConfigError>>, ValidatorConfigError>
+    {
+        if !is_valid_solana_pubkey(config_pubkey) {
+            return Err(ValidatorConfigError::InvalidConfig(format!(
+                "Invalid Solana public key: {config_pubkey}"
+            )));
+        }
+
+        let params = serde_json::json!([
+            config_pubkey,
+            {
+                "encoding": "base64+zstd",
+                "commitment": "confirmed"
+            }
+        ]);
+        let subscription = AccountSubscription {
+            client: self.clone(),
+            pubkey: config_pubkey.to_string(),
+            subscription: Subscription::open(
+                websocket_url(self.network.rpc_url()),
+                "accountSubscribe",
+                params,
+            )
+            .await?,
+        };
+
+        Ok(futures::stream::unfold(
+            subscription,
+            |mut subscription| async move {
+                let update = subscription.next_update().await;
+                Some((update, subscription))
+            },
+        ))
+    }
+}
+
+/// An event reported by [`ValidatorConfigWatcher::next_change`]
+#[derive(Debug, Clone)]
+pub enum ValidatorConfigChange {
+    /// A validator-info account was created or changed
+    Updated(ValidatorInfo),
+    /// The WebSocket connection was closed; the next call reconnects
+    Closed,
+}
+
+/// Live validator-info updates for the whole Config program over the RPC WebSocket API
+///
+/// Subscribes with `programSubscribe` to every account of the Config program, so any
+/// validator publishing or changing its info is reported without polling. Use
+/// [`ValidatorConfigClient::watch`] as a polling fallback for endpoints without WebSocket
+/// support.
+pub struct ValidatorConfigWatcher {
+    client: ValidatorConfigClient,
+    subscription: Subscription,
+}
+
+impl ValidatorConfigWatcher {
+    /// Connect to the WebSocket endpoint of `network` and subscribe to the Config program
+    ///
+    /// # Errors
+    /// Returns `ValidatorConfigError` if the connection or subscription fails
+    pub async fn new(network: SolanaNetwork) -> Result<Self, ValidatorConfigError> {
+        Self::with_client(&ValidatorConfigClient::new(network)).await
+    }
+
+    /// Subscribe using the network and configuration of an existing client
+    ///
+    /// Updates are decoded like fetched accounts, so the client's
+    /// [`ClientConfig`](crate::ClientConfig) options such as `include_empty_configs`,
+    /// `spam_filter` and `data_size_filter` apply.
+    ///
+    /// # Errors
+    /// Returns `ValidatorConfigError` if the connection or subscription fails
+    pub async fn with_client(client: &ValidatorConfigClient) -> Result<Self, ValidatorConfigError> {
+        let mut options = serde_json::json!({
+            "encoding": "base64+zstd",
+            "commitment": "confirmed"
+        });
+        if client.config.data_size_filter {
+            options["filters"] = serde_json::json!([{ "dataSize": VALIDATOR_INFO_ACCOUNT_SIZE }]);
+        }
+        let params = serde_json::json!([SOLANA_CONFIG_PROGRAM_ID, options]);
+        Ok(Self {
+            client: client.clone(),
+            subscription: Subscription::open(
+                websocket_url(client.network.rpc_url()),
+                "programSubscribe",
+                params,
+            )
+            .await?,
+        })
+    }
+
+    /// Wait for the next validator-info change
+    ///
+    /// Config accounts that are not validator info, and empty configs unless
+    /// `include_empty_configs` is set, are skipped. When the server closes the connection
+    /// this returns [`ValidatorConfigChange::Closed`]; calling it again reconnects and
+    /// resubscribes right away, waiting with exponential backoff from 500ms up to 30s only
+    /// after failed attempts. Changes made while disconnected are not reported.
+    ///
+    /// # Errors
+    /// Returns `ValidatorConfigError::WebSocket` if the connection fails (the next call
+    /// reconnects), or another `ValidatorConfigError` if reconnecting fails
+    pub async fn next_change(&mut self) -> Result<ValidatorConfigChange, ValidatorConfigError> {
+        loop {
+            match self.subscription.next_event().await? {
+                SubscriptionEvent::Text(text) => {
+                    if let Some(info) = self.parse_notification(&text) {
+                        return Ok(ValidatorConfigChange::Updated(info));
+                    }
+                }
+                SubscriptionEvent::Disconnected(None) => return Ok(ValidatorConfigChange::Closed),
+                SubscriptionEvent::Disconnected(Some(e)) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Decode a `programNotification`, or `None` for messages that are not validator info
+    fn parse_notification(&self, text: &str) -> Option<ValidatorInfo> {
+        let notification = match serde_json::from_str::<Notification<AccountEntry>>(text) {
+            Ok(notification) if notification.method == "programNotification" => notification,
+            _ => {
+                log::debug!("Ignoring WebSocket message: {text}");
+                return None;
+            }
+        };
+
+        match self.client.parse_account(notification.params.result.value) {
+            ParsedAccount::Validator(info) => Some(info),
+            ParsedAccount::Empty => None,
+            ParsedAccount::Unrecognized(pubkey) => {
+                log::debug!("Skipped Config account {pubkey} that is not validator info");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_websocket_url() {
+        assert_eq!(
+            websocket_url("https://api.mainnet-beta.solana.com"),
+            "wss://api.mainnet-beta.solana.com/"
+        );
+        assert_eq!(
+            websocket_url("http://127.0.0.1:8899"),
+            "ws://127.0.0.1:8900/"
+        );
+        assert_eq!(
+            websocket_url("https://rpc.example.com:8443/path?key=1"),
+            "wss://rpc.example.com:8444/path?key=1"
+        );
+        assert_eq!(websocket_url("not a url"), "not a url");
+    }
+}